@@ -1,22 +1,92 @@
-extern crate rand;
+use std::cmp::{min, max, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 
-use rand::seq::SliceRandom;
-use std::cmp::{min, max};
-use std::collections::{HashMap, HashSet};
+// disjoint-set with path compression and union-by-rank, used by is_cyclic,
+// connected_components, component_of and min_spanning_tree
+struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> UnionFind {
+        UnionFind {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+        }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    // returns false if x and y were already in the same set
+    fn union(&mut self, x: usize, y: usize) -> bool {
+        let root_x = self.find(x);
+        let root_y = self.find(y);
+        if root_x == root_y {
+            return false;
+        }
+        if self.rank[root_x] < self.rank[root_y] {
+            self.parent[root_x] = root_y;
+        } else if self.rank[root_x] > self.rank[root_y] {
+            self.parent[root_y] = root_x;
+        } else {
+            self.parent[root_y] = root_x;
+            self.rank[root_x] += 1;
+        }
+        true
+    }
+}
+
+// default weight assumed for edges added via add_edge, which carry no
+// explicit weight of their own
+const DEFAULT_WEIGHT: u32 = 1;
 
 #[derive(Clone)]
 pub struct Graph {
-    nodes: Vec<HashSet<usize>>
+    nodes: Vec<HashSet<usize>>,
+    weights: HashMap<(usize, usize), u32>,
+    directed: bool,
 }
 
 impl Graph {
     pub fn new(n: usize) -> Graph {
+        Graph::build(n, false)
+    }
+
+    // a directed graph: add_edge(s, t) only creates an s -> t edge, and
+    // get_neighbours(node) returns its direct successors
+    pub fn new_directed(n: usize) -> Graph {
+        Graph::build(n, true)
+    }
+
+    fn build(n: usize, directed: bool) -> Graph {
         let mut nodes = Vec::with_capacity(n);
         for _ in 0..n {
             nodes.push(HashSet::new());
         }
         Graph {
-            nodes
+            nodes,
+            weights: HashMap::new(),
+            directed,
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    // the key an edge between s and t is stored under: order matters for
+    // directed graphs, but not for undirected ones
+    fn edge_key(&self, s: usize, t: usize) -> (usize, usize) {
+        if self.directed {
+            (s, t)
+        } else {
+            (min(s, t), max(s, t))
         }
     }
 
@@ -26,19 +96,46 @@ impl Graph {
 
     pub fn add_edge(&mut self, s: usize, t: usize) {
         self.nodes[s].insert(t);
-        self.nodes[t].insert(s);
+        if !self.directed {
+            self.nodes[t].insert(s);
+        }
+    }
+
+    pub fn add_weighted_edge(&mut self, s: usize, t: usize, weight: u32) {
+        self.add_edge(s, t);
+        self.weights.insert(self.edge_key(s, t), weight);
+    }
+
+    // weight of the edge between s and t, or DEFAULT_WEIGHT if the edge
+    // exists but was never given one explicitly
+    pub fn weight(&self, s: usize, t: usize) -> Option<u32> {
+        if !self.adjecent(s, t) {
+            return None;
+        }
+        Some(*self.weights.get(&self.edge_key(s, t)).unwrap_or(&DEFAULT_WEIGHT))
     }
 
     pub fn remove_edge(&mut self, s: usize, t: usize) {
         self.nodes[s].remove(&t);
-        self.nodes[t].remove(&s);
+        if !self.directed {
+            self.nodes[t].remove(&s);
+        }
+        self.weights.remove(&self.edge_key(s, t));
     }
 
     pub fn remove_edges(&mut self, n: usize) {
         let neighbours = self.get_neighbours(n);
         for neighbour in neighbours {
             self.remove_edge(n, neighbour);
-        }    
+        }
+        if self.directed {
+            let incoming: Vec<usize> = (0..self.size())
+                .filter(|&other| self.nodes[other].contains(&n))
+                .collect();
+            for other in incoming {
+                self.remove_edge(other, n);
+            }
+        }
     }
 
     pub fn adjecent(&self, s: usize, t: usize) -> bool {
@@ -49,28 +146,53 @@ impl Graph {
         self.nodes.len()
     }
 
-    // really slow
-    pub fn is_cyclic(&self) -> bool {         
-        let mut open = Vec::with_capacity(self.size());            
-        let mut g = self.clone();       
-        let mut visited = HashSet::new();
-        for node in 0..g.size() {            
-            if visited.insert(node) {               
-                open.push(node);
-                while let Some(current) = open.pop() {                
-                    let neighbours = g.get_neighbours(current);                                
-                    g.remove_edges(current);
-                    for &n in &neighbours {                                    
-                        if visited.contains(&n) {
-                            return true;
-                        } else {
-                            visited.insert(n);
-                            open.push(n);
-                        }
+    pub fn is_cyclic(&self) -> bool {
+        if self.directed {
+            return self.is_cyclic_directed();
+        }
+        let mut uf = UnionFind::new(self.size());
+        for (s, t) in self.edges() {
+            if s == t {
+                return true;
+            }
+            if !uf.union(s, t) {
+                return true;
+            }
+        }
+        false
+    }
+
+    // DFS with a white/gray/black colouring: a cycle exists iff some
+    // edge leads back into a node that is gray (still on the current
+    // recursion stack), which also catches self-loops
+    fn is_cyclic_directed(&self) -> bool {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Color { White, Gray, Black }
+
+        let mut color = vec![Color::White; self.size()];
+        for start in 0..self.size() {
+            if color[start] != Color::White {
+                continue;
+            }
+            let mut stack = vec![(start, false)];
+            while let Some((node, leaving)) = stack.pop() {
+                if leaving {
+                    color[node] = Color::Black;
+                    continue;
+                }
+                if color[node] != Color::White {
+                    continue;
+                }
+                color[node] = Color::Gray;
+                stack.push((node, true));
+                for nbr in self.get_neighbours(node) {
+                    match color[nbr] {
+                        Color::Gray => return true,
+                        Color::White => stack.push((nbr, false)),
+                        Color::Black => {}
                     }
                 }
-                open.clear();
-            } 
+            }
         }
         false
     }
@@ -79,33 +201,265 @@ impl Graph {
         !self.is_cyclic()
     }
 
+    // number of connected components (groups of mutually reachable nodes);
+    // an isolated node counts as a component of its own
+    pub fn connected_components(&self) -> usize {
+        let mut uf = self.union_find();
+        let mut roots: HashSet<usize> = HashSet::new();
+        for node in 0..self.size() {
+            roots.insert(uf.find(node));
+        }
+        roots.len()
+    }
+
+    // id of the component `node` belongs to; two nodes are in the same
+    // component iff this returns the same value for both
+    pub fn component_of(&self, node: usize) -> usize {
+        // canonicalize on the smallest node in the class: `edges()` is
+        // built from a HashSet, so the raw union-find root for a given
+        // component can vary from call to call even though the
+        // partition itself doesn't
+        let mut uf = self.union_find();
+        let root = uf.find(node);
+        (0..self.size()).filter(|&n| uf.find(n) == root).min().unwrap()
+    }
+
+    fn union_find(&self) -> UnionFind {
+        let mut uf = UnionFind::new(self.size());
+        for (s, t) in self.edges() {
+            uf.union(s, t);
+        }
+        uf
+    }
+
     pub fn edges(&self) -> HashSet<(usize, usize)> {
         let mut out = HashSet::new();
         for (node, neighbours) in self.nodes.iter().enumerate() {
-            for &adjacent in neighbours {                
-                out.insert((min(node, adjacent), max(node, adjacent)));
+            for &adjacent in neighbours {
+                out.insert(self.edge_key(node, adjacent));
             }
-        } 
+        }
         out
     }
 
     pub fn count_edges(&self) -> usize {
         self.edges().len()
     }
-}
 
-fn make_spanning_tree(g: Graph) -> Graph {    
-    let mut h = Graph::new(g.size());
-    let mut edges: Vec<(usize, usize)> = g.edges().iter().cloned().collect();
-    let mut rng = rand::thread_rng();
-    edges.shuffle(&mut rng);    
-    for (s, t) in edges {
-        h.add_edge(s, t);
-        if h.is_cyclic() {
-            h.remove_edge(s, t);                        
+    // minimum-weight spanning forest, built with Kruskal's algorithm:
+    // sort edges ascending by weight, then keep an edge only if its
+    // endpoints are still in different union-find sets.
+    //
+    // undirected graphs only: a spanning tree/forest is a property of
+    // the undirected connectivity structure.
+    pub fn min_spanning_tree(&self) -> Graph {
+        assert!(!self.directed, "min_spanning_tree is only defined for undirected graphs");
+        let mut edges: Vec<(usize, usize)> = self.edges().into_iter().collect();
+        edges.sort_by_key(|&(s, t)| self.weight(s, t).unwrap_or(DEFAULT_WEIGHT));
+
+        let mut tree = Graph::new(self.size());
+        let mut uf = UnionFind::new(self.size());
+        for (s, t) in edges {
+            if uf.union(s, t) {
+                match self.weight(s, t) {
+                    Some(w) if w != DEFAULT_WEIGHT => tree.add_weighted_edge(s, t, w),
+                    _ => tree.add_edge(s, t),
+                }
+            }
+        }
+        tree
+    }
+
+    // a basis of the cycle space via Paton's algorithm: build a DFS tree
+    // per component and emit one cycle for every non-tree (back) edge.
+    // self-loops count as length-1 cycles. `root`, if given, is used as
+    // the starting node of the first component only.
+    //
+    // undirected graphs only: Paton's method relies on the `used` edge
+    // markers being symmetric, which directed edges are not.
+    pub fn cycle_basis(&self, root: Option<usize>) -> Vec<Vec<usize>> {
+        assert!(!self.directed, "cycle_basis is only defined for undirected graphs");
+        let mut gnodes: HashSet<usize> = (0..self.size()).collect();
+        let mut cycles: Vec<Vec<usize>> = Vec::new();
+        let mut root = root;
+
+        while !gnodes.is_empty() {
+            let start = match root {
+                Some(r) if gnodes.contains(&r) => r,
+                _ => *gnodes.iter().next().unwrap(),
+            };
+            let mut stack = vec![start];
+            let mut pred: HashMap<usize, usize> = HashMap::new();
+            pred.insert(start, start);
+            let mut used: HashMap<usize, HashSet<usize>> = HashMap::new();
+            used.insert(start, HashSet::new());
+
+            while let Some(z) = stack.pop() {
+                let zused = used[&z].clone();
+                for nbr in self.get_neighbours(z) {
+                    if let std::collections::hash_map::Entry::Vacant(e) = used.entry(nbr) {
+                        pred.insert(nbr, z);
+                        stack.push(nbr);
+                        let mut tree_edge = HashSet::new();
+                        tree_edge.insert(z);
+                        e.insert(tree_edge);
+                    } else if nbr == z {
+                        cycles.push(vec![z]);
+                    } else if !zused.contains(&nbr) {
+                        let pred_of_nbr = used[&nbr].clone();
+                        let mut cycle = vec![nbr, z];
+                        let mut p = pred[&z];
+                        while !pred_of_nbr.contains(&p) {
+                            cycle.push(p);
+                            p = pred[&p];
+                        }
+                        cycle.push(p);
+                        cycles.push(cycle);
+                        used.get_mut(&nbr).unwrap().insert(z);
+                    }
+                }
+            }
+            for node in pred.keys() {
+                gnodes.remove(node);
+            }
+            root = None;
         }
+        cycles
+    }
+
+    // shortest distance from source to every reachable node, via Dijkstra
+    // with a lazy-deletion binary heap
+    pub fn shortest_paths(&self, source: usize) -> Vec<Option<u32>> {
+        let mut dist: Vec<Option<u32>> = vec![None; self.size()];
+        dist[source] = Some(0);
+
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((0u32, source)));
+
+        while let Some(Reverse((d, u))) = heap.pop() {
+            if Some(d) != dist[u] {
+                continue;
+            }
+            for v in self.get_neighbours(u) {
+                let new_dist = d + self.weight(u, v).unwrap_or(DEFAULT_WEIGHT);
+                if dist[v].is_none_or(|cur| new_dist < cur) {
+                    dist[v] = Some(new_dist);
+                    heap.push(Reverse((new_dist, v)));
+                }
+            }
+        }
+        dist
+    }
+
+    // shortest distance and an actual path from source to target, or
+    // None if target is unreachable
+    pub fn shortest_path(&self, source: usize, target: usize) -> Option<(u32, Vec<usize>)> {
+        let mut dist: Vec<Option<u32>> = vec![None; self.size()];
+        let mut prev: Vec<Option<usize>> = vec![None; self.size()];
+        dist[source] = Some(0);
+
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((0u32, source)));
+
+        while let Some(Reverse((d, u))) = heap.pop() {
+            if Some(d) != dist[u] {
+                continue;
+            }
+            if u == target {
+                break;
+            }
+            for v in self.get_neighbours(u) {
+                let new_dist = d + self.weight(u, v).unwrap_or(DEFAULT_WEIGHT);
+                if dist[v].is_none_or(|cur| new_dist < cur) {
+                    dist[v] = Some(new_dist);
+                    prev[v] = Some(u);
+                    heap.push(Reverse((new_dist, v)));
+                }
+            }
+        }
+
+        dist[target].map(|d| {
+            let mut path = vec![target];
+            let mut node = target;
+            while let Some(p) = prev[node] {
+                path.push(p);
+                node = p;
+            }
+            path.reverse();
+            (d, path)
+        })
+    }
+
+    // every node reachable from `node` by following directed edges
+    // (not including `node` itself, unless a cycle leads back to it)
+    pub fn descendants(&self, node: usize) -> HashSet<usize> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<usize> = self.get_neighbours(node).into_iter().collect();
+        while let Some(n) = stack.pop() {
+            if seen.insert(n) {
+                stack.extend(self.get_neighbours(n));
+            }
+        }
+        seen
+    }
+
+    // the minimal directed edge set that preserves reachability: for a
+    // DAG, an edge (u, v) is redundant whenever v is also reachable from
+    // u through some other direct successor of u.
+    //
+    // DAGs only: on a graph with a cycle, every node on the cycle is its
+    // own descendant, so edges within the cycle would be misclassified
+    // as redundant.
+    pub fn transitive_reduction(&self) -> Graph {
+        assert!(self.directed, "transitive_reduction is only defined for directed graphs");
+        assert!(!self.is_cyclic(), "transitive_reduction is only defined for acyclic graphs");
+        let mut reduced = Graph::new_directed(self.size());
+        let mut descendants_cache: HashMap<usize, HashSet<usize>> = HashMap::new();
+
+        for u in 0..self.size() {
+            let successors = self.get_neighbours(u);
+            let mut keep = successors.clone();
+            for v in &successors {
+                let reachable_via_v = descendants_cache
+                    .entry(*v)
+                    .or_insert_with(|| self.descendants(*v));
+                for w in reachable_via_v.iter() {
+                    keep.remove(w);
+                }
+            }
+            for v in keep {
+                reduced.add_edge(u, v);
+            }
+        }
+        reduced
+    }
+
+    // serializes the graph in Graphviz DOT format: `graph G { ... }` with
+    // `a -- b;` edges for the undirected case, `digraph G { ... }` with
+    // `a -> b;` edges when directed, weighted edges get a `[label="w"]`,
+    // and isolated nodes are emitted bare so they still show up
+    pub fn to_dot(&self) -> String {
+        let edge_op = if self.directed { "->" } else { "--" };
+        let mut out = String::new();
+        out.push_str(if self.directed { "digraph G {\n" } else { "graph G {\n" });
+
+        let mut connected = HashSet::new();
+        for (s, t) in self.edges() {
+            connected.insert(s);
+            connected.insert(t);
+            match self.weights.get(&(s, t)) {
+                Some(w) => out.push_str(&format!("    {} {} {} [label=\"{}\"];\n", s, edge_op, t, w)),
+                None => out.push_str(&format!("    {} {} {};\n", s, edge_op, t)),
+            }
+        }
+        for node in 0..self.size() {
+            if !connected.contains(&node) {
+                out.push_str(&format!("    {};\n", node));
+            }
+        }
+        out.push_str("}\n");
+        out
     }
-    h
 }
 
 
@@ -197,8 +551,367 @@ mod tests {
             g.add_edge(m, m + 1);
             g.add_edge(m + 1, m + 2);
             g.add_edge(m + 2, m + 3);
-            g.add_edge(m + 3, m + 4);            
+            g.add_edge(m + 3, m + 4);
         }
         assert!(g.is_acyclic());
     }
+
+    #[test]
+    fn connected_components_counts_one_per_isolated_node() {
+        use Graph;
+        let g = Graph::new(5);
+        assert_eq!(g.connected_components(), 5);
+    }
+
+    #[test]
+    fn connected_components_counts_groups() {
+        use Graph;
+        let mut g = Graph::new(6);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(3, 4);
+        // {0,1,2}, {3,4}, {5} => 3 components
+        assert_eq!(g.connected_components(), 3);
+    }
+
+    #[test]
+    fn component_of_agrees_within_a_component() {
+        use Graph;
+        let mut g = Graph::new(6);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(3, 4);
+        assert_eq!(g.component_of(0), g.component_of(1));
+        assert_eq!(g.component_of(1), g.component_of(2));
+        assert_ne!(g.component_of(0), g.component_of(3));
+        assert_ne!(g.component_of(3), g.component_of(5));
+    }
+
+    #[test]
+    fn unweighted_edge_has_default_weight() {
+        use Graph;
+        let mut g = Graph::new(2);
+        g.add_edge(0, 1);
+        assert_eq!(g.weight(0, 1), Some(1));
+        assert_eq!(g.weight(1, 0), Some(1));
+        assert_eq!(g.weight(0, 0), None);
+    }
+
+    #[test]
+    fn weighted_edge_keeps_its_weight() {
+        use Graph;
+        let mut g = Graph::new(2);
+        g.add_weighted_edge(0, 1, 42);
+        assert_eq!(g.weight(0, 1), Some(42));
+    }
+
+    #[test]
+    fn removing_edge_clears_its_weight() {
+        use Graph;
+        let mut g = Graph::new(2);
+        g.add_weighted_edge(0, 1, 42);
+        g.remove_edge(0, 1);
+        assert_eq!(g.weight(0, 1), None);
+    }
+
+    #[test]
+    fn min_spanning_tree_picks_cheapest_edges() {
+        use Graph;
+        // a triangle where the heaviest edge should be dropped
+        let mut g = Graph::new(3);
+        g.add_weighted_edge(0, 1, 1);
+        g.add_weighted_edge(1, 2, 1);
+        g.add_weighted_edge(0, 2, 5);
+        let tree = g.min_spanning_tree();
+        assert_eq!(tree.count_edges(), 2);
+        assert!(tree.is_acyclic());
+        assert!(tree.adjecent(0, 1));
+        assert!(tree.adjecent(1, 2));
+        assert!(!tree.adjecent(0, 2));
+    }
+
+    #[test]
+    fn min_spanning_tree_is_a_forest_for_disconnected_graphs() {
+        use Graph;
+        let mut g = Graph::new(6);
+        g.add_weighted_edge(0, 1, 3);
+        g.add_weighted_edge(1, 2, 1);
+        g.add_weighted_edge(0, 2, 2);
+        g.add_weighted_edge(3, 4, 1);
+        let tree = g.min_spanning_tree();
+        assert_eq!(tree.count_edges(), 3);
+        assert!(tree.is_acyclic());
+        assert_eq!(tree.connected_components(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "undirected")]
+    fn min_spanning_tree_rejects_directed_graphs() {
+        use Graph;
+        let mut g = Graph::new_directed(3);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.min_spanning_tree();
+    }
+
+    #[test]
+    fn cycle_basis_empty_for_acyclic_graph() {
+        use Graph;
+        let mut g = Graph::new(4);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 3);
+        assert!(g.cycle_basis(None).is_empty());
+    }
+
+    #[test]
+    fn cycle_basis_finds_self_loop() {
+        use Graph;
+        let mut g = Graph::new(1);
+        g.add_edge(0, 0);
+        assert_eq!(g.cycle_basis(None), vec![vec![0]]);
+    }
+
+    #[test]
+    fn cycle_basis_finds_triangle() {
+        use Graph;
+        let mut g = Graph::new(3);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 0);
+        let basis = g.cycle_basis(Some(0));
+        assert_eq!(basis.len(), 1);
+        let mut nodes = basis[0].clone();
+        nodes.sort();
+        assert_eq!(nodes, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn cycle_basis_size_matches_e_minus_v_plus_components() {
+        use Graph;
+        // two triangles sharing no nodes, plus a pendant edge 6-0 that
+        // hangs off the first triangle and closes no cycle
+        let mut g = Graph::new(7);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 0);
+        g.add_edge(3, 4);
+        g.add_edge(4, 5);
+        g.add_edge(5, 3);
+        g.add_edge(6, 0);
+        let expected = g.count_edges() as isize - g.size() as isize
+            + g.connected_components() as isize;
+        assert_eq!(g.cycle_basis(None).len() as isize, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "undirected")]
+    fn cycle_basis_rejects_directed_graphs() {
+        use Graph;
+        let mut g = Graph::new_directed(3);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 0);
+        g.cycle_basis(None);
+    }
+
+    #[test]
+    fn shortest_paths_unweighted_counts_hops() {
+        use Graph;
+        let mut g = Graph::new(4);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 3);
+        assert_eq!(g.shortest_paths(0), vec![Some(0), Some(1), Some(2), Some(3)]);
+    }
+
+    #[test]
+    fn shortest_paths_picks_cheaper_route() {
+        use Graph;
+        let mut g = Graph::new(4);
+        g.add_weighted_edge(0, 1, 5);
+        g.add_weighted_edge(0, 2, 1);
+        g.add_weighted_edge(2, 1, 1);
+        g.add_weighted_edge(1, 3, 1);
+        let dist = g.shortest_paths(0);
+        assert_eq!(dist[1], Some(2));
+        assert_eq!(dist[3], Some(3));
+    }
+
+    #[test]
+    fn shortest_paths_unreachable_node_is_none() {
+        use Graph;
+        let mut g = Graph::new(3);
+        g.add_edge(0, 1);
+        assert_eq!(g.shortest_paths(0), vec![Some(0), Some(1), None]);
+    }
+
+    #[test]
+    fn shortest_path_reconstructs_route() {
+        use Graph;
+        let mut g = Graph::new(4);
+        g.add_weighted_edge(0, 1, 5);
+        g.add_weighted_edge(0, 2, 1);
+        g.add_weighted_edge(2, 1, 1);
+        g.add_weighted_edge(1, 3, 1);
+        let (dist, path) = g.shortest_path(0, 3).unwrap();
+        assert_eq!(dist, 3);
+        assert_eq!(path, vec![0, 2, 1, 3]);
+    }
+
+    #[test]
+    fn shortest_path_source_equals_target() {
+        use Graph;
+        let g = Graph::new(1);
+        assert_eq!(g.shortest_path(0, 0), Some((0, vec![0])));
+    }
+
+    #[test]
+    fn shortest_path_none_when_unreachable() {
+        use Graph;
+        let mut g = Graph::new(3);
+        g.add_edge(0, 1);
+        assert_eq!(g.shortest_path(0, 2), None);
+    }
+
+    #[test]
+    fn directed_edge_is_one_way() {
+        use Graph;
+        let mut g = Graph::new_directed(2);
+        g.add_edge(0, 1);
+        assert!(g.adjecent(0, 1));
+        assert!(!g.adjecent(1, 0));
+    }
+
+    #[test]
+    fn directed_diamond_is_acyclic() {
+        use Graph;
+        let mut g = Graph::new_directed(4);
+        g.add_edge(0, 1);
+        g.add_edge(0, 2);
+        g.add_edge(1, 3);
+        g.add_edge(2, 3);
+        assert!(g.is_acyclic());
+    }
+
+    #[test]
+    fn directed_back_edge_is_cyclic() {
+        use Graph;
+        let mut g = Graph::new_directed(3);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 0);
+        assert!(g.is_cyclic());
+    }
+
+    #[test]
+    fn directed_self_loop_is_cyclic() {
+        use Graph;
+        let mut g = Graph::new_directed(1);
+        g.add_edge(0, 0);
+        assert!(g.is_cyclic());
+    }
+
+    #[test]
+    fn remove_edges_drops_incoming_edges_in_directed_mode() {
+        use Graph;
+        let mut g = Graph::new_directed(3);
+        g.add_edge(0, 1);
+        g.add_edge(2, 1);
+        g.remove_edges(1);
+        assert!(!g.adjecent(0, 1));
+        assert!(!g.adjecent(2, 1));
+    }
+
+    #[test]
+    fn descendants_follows_directed_edges() {
+        use Graph;
+        let mut g = Graph::new_directed(4);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(0, 3);
+        let mut desc: Vec<usize> = g.descendants(0).into_iter().collect();
+        desc.sort();
+        assert_eq!(desc, vec![1, 2, 3]);
+        assert!(g.descendants(2).is_empty());
+    }
+
+    #[test]
+    fn transitive_reduction_drops_redundant_shortcut() {
+        use Graph;
+        // 0 -> 1 -> 2, plus a redundant shortcut 0 -> 2
+        let mut g = Graph::new_directed(3);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(0, 2);
+        let reduced = g.transitive_reduction();
+        assert!(reduced.adjecent(0, 1));
+        assert!(reduced.adjecent(1, 2));
+        assert!(!reduced.adjecent(0, 2));
+        assert_eq!(reduced.count_edges(), 2);
+    }
+
+    #[test]
+    fn transitive_reduction_keeps_edges_that_are_not_shortcuts() {
+        use Graph;
+        let mut g = Graph::new_directed(3);
+        g.add_edge(0, 1);
+        g.add_edge(0, 2);
+        let reduced = g.transitive_reduction();
+        assert!(reduced.adjecent(0, 1));
+        assert!(reduced.adjecent(0, 2));
+        assert_eq!(reduced.count_edges(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "directed")]
+    fn transitive_reduction_rejects_undirected_graphs() {
+        use Graph;
+        let mut g = Graph::new(3);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.transitive_reduction();
+    }
+
+    #[test]
+    #[should_panic(expected = "acyclic")]
+    fn transitive_reduction_rejects_cyclic_graphs() {
+        use Graph;
+        let mut g = Graph::new_directed(3);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 0);
+        g.add_edge(0, 2);
+        g.transitive_reduction();
+    }
+
+    #[test]
+    fn to_dot_undirected_with_isolated_node() {
+        use Graph;
+        let mut g = Graph::new(3);
+        g.add_edge(0, 1);
+        let dot = g.to_dot();
+        assert!(dot.starts_with("graph G {\n"));
+        assert!(dot.contains("0 -- 1;"));
+        assert!(dot.contains("2;"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn to_dot_weighted_edge_has_label() {
+        use Graph;
+        let mut g = Graph::new(2);
+        g.add_weighted_edge(0, 1, 7);
+        assert!(g.to_dot().contains("0 -- 1 [label=\"7\"];"));
+    }
+
+    #[test]
+    fn to_dot_directed_uses_arrow() {
+        use Graph;
+        let mut g = Graph::new_directed(2);
+        g.add_edge(0, 1);
+        let dot = g.to_dot();
+        assert!(dot.starts_with("digraph G {\n"));
+        assert!(dot.contains("0 -> 1;"));
+    }
 }